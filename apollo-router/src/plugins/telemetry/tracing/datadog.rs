@@ -1,8 +1,21 @@
 //! Configuration for datadog tracing.
 use lazy_static::lazy_static;
 use std::collections::HashMap;
+use opentelemetry::propagation::text_map_propagator::FieldIter;
+use opentelemetry::propagation::Extractor;
+use opentelemetry::propagation::Injector;
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::SpanContext;
+use opentelemetry::trace::SpanId;
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry::trace::TraceFlags;
+use opentelemetry::trace::TraceId;
+use opentelemetry::trace::TraceState;
+use opentelemetry::Context;
 use opentelemetry::Key;
 use opentelemetry::Value;
+use opentelemetry::sdk::propagation::TextMapCompositePropagator;
+use opentelemetry::sdk::propagation::TraceContextPropagator;
 use opentelemetry::sdk::trace::BatchSpanProcessor;
 use opentelemetry::sdk::trace::Builder;
 use schemars::JsonSchema;
@@ -19,6 +32,10 @@ use crate::plugins::telemetry::tracing::BatchProcessorConfig;
 use crate::plugins::telemetry::tracing::SpanProcessorExt;
 use crate::plugins::telemetry::tracing::TracingConfigurator;
 
+mod agentless;
+
+use agentless::AgentlessSpanExporter;
+
 lazy_static! {
     static ref SPAN_NAME_MAPPING: HashMap<&'static str, &'static str> = {
         let mut map = HashMap::new();
@@ -45,10 +62,176 @@ lazy_static! {
     };
 }
 
+/// Resolves a span's exported name: configured override, then built-in default,
+/// then the raw span name if neither has an entry.
+pub(super) fn resolve_span_name<'a>(overrides: &'a HashMap<String, String>, name: &'a str) -> &'a str {
+    overrides
+        .get(name)
+        .map(String::as_str)
+        .or_else(|| SPAN_NAME_MAPPING.get(name).copied())
+        .unwrap_or(name)
+}
+
+/// Resolves a span's exported resource: look up the configured override, then the
+/// built-in default, for an attribute key; resolve that key against the span's
+/// attributes and stringify it, falling back to the raw span name.
+pub(super) fn resolve_span_resource<'a>(
+    overrides: &'a HashMap<String, String>,
+    attributes: &'a opentelemetry::sdk::trace::EvictedHashMap,
+    name: &'a str,
+) -> &'a str {
+    overrides
+        .get(name)
+        .map(String::as_str)
+        .or_else(|| SPAN_RESOURCE_ATTRIBUTE_MAPPING.get(name).copied())
+        .and_then(|key| attributes.get(&Key::from(key.to_owned())))
+        .and_then(|value| match value {
+            Value::String(value) => Some(value.as_str()),
+            _ => None,
+        })
+        .unwrap_or(name)
+}
+
+/// The Datadog trace intake format used to serialize spans to the agent.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ApiVersion {
+    /// The legacy v0.3 format, one MessagePack map per span.
+    #[default]
+    #[serde(rename = "v03")]
+    Version03,
+    /// The v0.5 format: a two-element MessagePack array where element 0 is a
+    /// deduplicated string table and spans reference it via integer indices,
+    /// which shrinks payloads for high-span-count requests.
+    #[serde(rename = "v05")]
+    Version05,
+}
+
+impl From<ApiVersion> for opentelemetry_datadog::ApiVersion {
+    fn from(value: ApiVersion) -> Self {
+        match value {
+            ApiVersion::Version03 => opentelemetry_datadog::ApiVersion::Version03,
+            ApiVersion::Version05 => opentelemetry_datadog::ApiVersion::Version05,
+        }
+    }
+}
+
+/// How spans are shipped to Datadog.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ExportMode {
+    /// Send spans to a local Datadog agent (the default).
+    #[default]
+    Agent,
+    /// Send spans directly to Datadog's trace intake over HTTPS, without an agent.
+    Agentless,
+}
+
+/// The Datadog site to ship spans to in agentless mode.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Site {
+    /// trace.agent.datadoghq.com
+    #[default]
+    Com,
+    /// trace.agent.datadoghq.eu
+    Eu,
+}
+
+impl Site {
+    fn base_url(&self) -> &'static str {
+        match self {
+            Site::Com => "https://trace.agent.datadoghq.com/",
+            Site::Eu => "https://trace.agent.datadoghq.eu/",
+        }
+    }
+}
+
+const DATADOG_TRACE_ID_HEADER: &str = "x-datadog-trace-id";
+const DATADOG_PARENT_ID_HEADER: &str = "x-datadog-parent-id";
+const DATADOG_SAMPLING_PRIORITY_HEADER: &str = "x-datadog-sampling-priority";
+
+/// A `TextMapPropagator` for Datadog's `x-datadog-*` trace context headers, so
+/// traces stitch across calls to/from Datadog-instrumented subgraphs.
+///
+/// Datadog trace and span IDs are 64-bit decimal integers, unlike the 128-bit hex
+/// IDs of W3C trace context, so inject renders the lower 64 bits of the OTel
+/// `TraceId` as a decimal string, and extract parses it back the same way.
+#[derive(Debug, Default)]
+pub(crate) struct DatadogPropagator;
+
+impl DatadogPropagator {
+    fn extract_span_context(&self, extractor: &dyn Extractor) -> Option<SpanContext> {
+        let trace_id = extractor
+            .get(DATADOG_TRACE_ID_HEADER)?
+            .parse::<u64>()
+            .ok()?;
+        let span_id = extractor
+            .get(DATADOG_PARENT_ID_HEADER)?
+            .parse::<u64>()
+            .ok()?;
+        // 1/2 = keep, 0/-1 = drop. A missing header means no upstream decision was made,
+        // so default to sampled; a header that fails to parse is treated as a drop rather
+        // than silently overriding what may have been an intentional drop decision.
+        let sampled = match extractor.get(DATADOG_SAMPLING_PRIORITY_HEADER) {
+            None => true,
+            Some(value) => value.parse::<i8>().map(|priority| priority > 0).unwrap_or(false),
+        };
+
+        Some(SpanContext::new(
+            TraceId::from_u128(trace_id as u128),
+            SpanId::from_u64(span_id),
+            if sampled {
+                TraceFlags::SAMPLED
+            } else {
+                TraceFlags::default()
+            },
+            true,
+            TraceState::default(),
+        ))
+    }
+}
+
+impl TextMapPropagator for DatadogPropagator {
+    fn inject_context(&self, cx: &Context, injector: &mut dyn Injector) {
+        let span_context = cx.span().span_context().clone();
+        if span_context.is_valid() {
+            injector.set(
+                DATADOG_TRACE_ID_HEADER,
+                (span_context.trace_id().to_u128() as u64).to_string(),
+            );
+            injector.set(
+                DATADOG_PARENT_ID_HEADER,
+                span_context.span_id().to_u64().to_string(),
+            );
+            injector.set(
+                DATADOG_SAMPLING_PRIORITY_HEADER,
+                if span_context.is_sampled() { "1" } else { "0" }.to_string(),
+            );
+        }
+    }
+
+    fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+        match self.extract_span_context(extractor) {
+            Some(span_context) => cx.with_remote_span_context(span_context),
+            None => cx.clone(),
+        }
+    }
+
+    fn fields(&self) -> FieldIter<'_> {
+        static FIELDS: [&str; 3] = [
+            DATADOG_TRACE_ID_HEADER,
+            DATADOG_PARENT_ID_HEADER,
+            DATADOG_SAMPLING_PRIORITY_HEADER,
+        ];
+        FieldIter::new(&FIELDS)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub(crate) struct Config {
-    /// The endpoint to send to
+    /// The endpoint to send to. Only used when `mode` is `agent`.
     #[serde(deserialize_with = "deser_endpoint")]
     #[schemars(schema_with = "agent_endpoint")]
     pub(crate) endpoint: AgentEndpoint,
@@ -56,35 +239,75 @@ pub(crate) struct Config {
     /// batch processor configuration
     #[serde(default)]
     pub(crate) batch_processor: BatchProcessorConfig,
+
+    /// The trace payload API version to use when exporting to the Datadog agent.
+    #[serde(default)]
+    pub(crate) api_version: ApiVersion,
+
+    /// Custom span name mappings, merged over (and overriding) the built-in defaults.
+    #[serde(default)]
+    pub(crate) span_name_mappings: HashMap<String, String>,
+
+    /// Custom span resource attribute mappings, merged over (and overriding) the built-in defaults.
+    #[serde(default)]
+    pub(crate) resource_mappings: HashMap<String, String>,
+
+    /// Whether to export via a local Datadog agent or directly to Datadog (agentless).
+    #[serde(default)]
+    pub(crate) mode: ExportMode,
+
+    /// The Datadog site to use when `mode` is `agentless`.
+    #[serde(default)]
+    pub(crate) site: Site,
+
+    /// The Datadog API key, required when `mode` is `agentless`. Supports
+    /// `${env.VAR_NAME}` expansion so the key doesn't need to be written to disk.
+    pub(crate) api_key: Option<String>,
+
+    /// Enable Datadog trace-context propagation (`x-datadog-*` headers) so traces
+    /// stitch across calls to/from Datadog-instrumented subgraphs.
+    #[serde(default)]
+    pub(crate) enable_propagation: bool,
 }
 
-impl TracingConfigurator for Config {
-    fn apply(&self, builder: Builder, trace_config: &Trace) -> Result<Builder, BoxError> {
-        tracing::info!("configuring Datadog tracing: {}", self.batch_processor);
+impl Config {
+    /// Builds the `reqwest::Client` used for agentless export, with the `DD-Api-Key`
+    /// header baked in. Only valid to call when `mode` is `agentless`.
+    fn agentless_client(&self) -> Result<reqwest::Client, BoxError> {
+        let api_key = self
+            .api_key
+            .clone()
+            .ok_or("datadog agentless export requires `api_key` to be set")?;
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "DD-Api-Key",
+            reqwest::header::HeaderValue::from_str(&api_key)
+                .map_err(|e| format!("invalid datadog api_key: {e}"))?,
+        );
+        Ok(reqwest::Client::builder().default_headers(headers).build()?)
+    }
+
+    /// The Datadog direct-trace-intake URL spans are POSTed to in agentless mode.
+    fn agentless_endpoint(&self) -> String {
+        format!("{}api/v0.2/traces", self.site.base_url())
+    }
+
+    fn apply_agent(&self, builder: Builder, trace_config: &Trace) -> Result<Builder, BoxError> {
         let url = match &self.endpoint {
             AgentEndpoint::Default(_) => None,
-            AgentEndpoint::Url(s) => Some(s),
+            AgentEndpoint::Url(s) => Some(s.to_string()),
         };
+
         let exporter = opentelemetry_datadog::new_pipeline()
-            .with(&url, |b, e| {
-                b.with_agent_endpoint(e.to_string().trim_end_matches('/'))
-            })
+            .with(&url, |b, e| b.with_agent_endpoint(e.trim_end_matches('/')))
             .with_service_name(trace_config.service_name.clone())
-            .with_name_mapping(|span, _model_config|
-                SPAN_NAME_MAPPING.get(span.name.as_ref())
-                    .unwrap_or(&"apollo_router")
-            )
-            .with_resource_mapping(|span, _model_config|
-                SPAN_RESOURCE_ATTRIBUTE_MAPPING
-                    .get(span.name.as_ref())
-                    .and_then(|key| span.attributes.get(&Key::from_static_str(key)))
-                    .and_then(|value| match value {
-                        Value::String(value) => Some(value.as_str()),
-                        _ => None,
-                    })
-                    .unwrap_or(span.name.as_ref())
-
-            )
+            .with_version(self.api_version.into())
+            .with_name_mapping(|span, _model_config| {
+                resolve_span_name(&self.span_name_mappings, span.name.as_ref())
+            })
+            .with_resource_mapping(|span, _model_config| {
+                resolve_span_resource(&self.resource_mappings, &span.attributes, span.name.as_ref())
+            })
             .with_trace_config(trace_config.into())
             .build_exporter()?;
 
@@ -95,10 +318,48 @@ impl TracingConfigurator for Config {
                 .filtered(),
         ))
     }
+
+    /// Exports directly to Datadog's trace intake as protobuf, bypassing the
+    /// agent-oriented msgpack pipeline used by [`Config::apply_agent`].
+    fn apply_agentless(&self, builder: Builder, trace_config: &Trace) -> Result<Builder, BoxError> {
+        let exporter = AgentlessSpanExporter::new(
+            self.agentless_client()?,
+            self.agentless_endpoint(),
+            trace_config.service_name.clone(),
+            self.span_name_mappings.clone(),
+            self.resource_mappings.clone(),
+        );
+
+        Ok(builder.with_span_processor(
+            BatchSpanProcessor::builder(exporter, opentelemetry::runtime::Tokio)
+                .with_batch_config(self.batch_processor.clone().into())
+                .build()
+                .filtered(),
+        ))
+    }
+}
+
+impl TracingConfigurator for Config {
+    fn apply(&self, builder: Builder, trace_config: &Trace) -> Result<Builder, BoxError> {
+        tracing::info!("configuring Datadog tracing: {}", self.batch_processor);
+
+        if self.enable_propagation {
+            opentelemetry::global::set_text_map_propagator(TextMapCompositePropagator::new(vec![
+                Box::new(TraceContextPropagator::new()),
+                Box::new(DatadogPropagator),
+            ]));
+        }
+
+        match self.mode {
+            ExportMode::Agent => self.apply_agent(builder, trace_config),
+            ExportMode::Agentless => self.apply_agentless(builder, trace_config),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use opentelemetry::sdk::trace::EvictedHashMap;
     use reqwest::Url;
 
     use super::*;
@@ -124,4 +385,315 @@ mod tests {
             config.endpoint
         );
     }
+
+    #[test]
+    fn api_version_configuration() {
+        let config: Config = serde_yaml::from_str("endpoint: default").unwrap();
+        assert_eq!(ApiVersion::Version03, config.api_version);
+
+        let config: Config = serde_yaml::from_str(
+            r#"
+            endpoint: default
+            api_version: v05
+            "#,
+        )
+        .unwrap();
+        assert_eq!(ApiVersion::Version05, config.api_version);
+
+        let error = serde_yaml::from_str::<Config>(
+            r#"
+            endpoint: default
+            api_version: v42
+            "#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("unknown variant"));
+    }
+
+    #[test]
+    fn span_name_mappings_configuration() {
+        let config: Config = serde_yaml::from_str(
+            r#"
+            endpoint: default
+            span_name_mappings:
+                request: custom.request
+                my_custom_span: my_custom_span.mapped
+            "#,
+        )
+        .unwrap();
+
+        // overrides the built-in default for "request"
+        assert_eq!(
+            Some(&"custom.request".to_string()),
+            config.span_name_mappings.get("request")
+        );
+        // extends the defaults with a new span name
+        assert_eq!(
+            Some(&"my_custom_span.mapped".to_string()),
+            config.span_name_mappings.get("my_custom_span")
+        );
+        // built-in defaults are untouched
+        assert_eq!(
+            Some(&"supergraph.router"),
+            SPAN_NAME_MAPPING.get("router")
+        );
+    }
+
+    #[test]
+    fn resource_mappings_configuration() {
+        let config: Config = serde_yaml::from_str(
+            r#"
+            endpoint: default
+            resource_mappings:
+                request: http.target
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(&"http.target".to_string()),
+            config.resource_mappings.get("request")
+        );
+        assert_eq!(
+            Some(&"http.route"),
+            SPAN_RESOURCE_ATTRIBUTE_MAPPING.get("request")
+        );
+    }
+
+    #[test]
+    fn resolve_span_name_precedence() {
+        let mut overrides = HashMap::new();
+        overrides.insert("request".to_string(), "custom.request".to_string());
+
+        // an override wins over the built-in default
+        assert_eq!("custom.request", resolve_span_name(&overrides, "request"));
+        // no override: falls back to the built-in default
+        assert_eq!("supergraph.router", resolve_span_name(&overrides, "router"));
+        // neither an override nor a built-in default: falls back to the raw name
+        assert_eq!("my_custom_span", resolve_span_name(&overrides, "my_custom_span"));
+    }
+
+    #[test]
+    fn resolve_span_resource_precedence() {
+        let mut overrides = HashMap::new();
+        overrides.insert("request".to_string(), "http.target".to_string());
+
+        let mut attributes = EvictedHashMap::new(10, "request");
+        attributes.insert(Key::new("http.target").string("/custom"));
+        attributes.insert(Key::new("http.route").string("/default"));
+
+        // an override wins over the built-in default, and is resolved against the attributes
+        assert_eq!(
+            "/custom",
+            resolve_span_resource(&overrides, &attributes, "request")
+        );
+
+        let mut attributes = EvictedHashMap::new(10, "supergraph");
+        attributes.insert(Key::new("graphql.operation.name").string("MyQuery"));
+
+        // no override: falls back to the built-in default mapping
+        assert_eq!(
+            "MyQuery",
+            resolve_span_resource(&HashMap::new(), &attributes, "supergraph")
+        );
+
+        // neither an override nor a built-in mapping: falls back to the raw name
+        let attributes = EvictedHashMap::new(10, "unmapped");
+        assert_eq!(
+            "unmapped",
+            resolve_span_resource(&HashMap::new(), &attributes, "unmapped")
+        );
+    }
+
+    #[test]
+    fn agent_mode_configuration() {
+        let config: Config = serde_yaml::from_str("endpoint: default").unwrap();
+        assert_eq!(ExportMode::Agent, config.mode);
+        assert_eq!(None, config.api_key);
+    }
+
+    #[test]
+    fn agentless_mode_configuration() {
+        let config: Config = serde_yaml::from_str(
+            r#"
+            endpoint: default
+            mode: agentless
+            site: eu
+            api_key: "${env.DD_API_KEY}"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(ExportMode::Agentless, config.mode);
+        assert_eq!(Site::Eu, config.site);
+        assert_eq!(Some("${env.DD_API_KEY}".to_string()), config.api_key);
+    }
+
+    #[test]
+    fn site_base_url_resolution() {
+        assert_eq!("https://trace.agent.datadoghq.com/", Site::Com.base_url());
+        assert_eq!("https://trace.agent.datadoghq.eu/", Site::Eu.base_url());
+    }
+
+    #[test]
+    fn agentless_requires_api_key() {
+        let config: Config = serde_yaml::from_str(
+            r#"
+            endpoint: default
+            mode: agentless
+            "#,
+        )
+        .unwrap();
+        assert!(config.agentless_client().is_err());
+
+        let config: Config = serde_yaml::from_str(
+            r#"
+            endpoint: default
+            mode: agentless
+            api_key: a-valid-key
+            "#,
+        )
+        .unwrap();
+        assert!(config.agentless_client().is_ok());
+    }
+
+    #[test]
+    fn agentless_endpoint_resolution() {
+        let config: Config = serde_yaml::from_str(
+            r#"
+            endpoint: default
+            mode: agentless
+            site: eu
+            api_key: a-valid-key
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            "https://trace.agent.datadoghq.eu/api/v0.2/traces",
+            config.agentless_endpoint()
+        );
+    }
+
+    #[test]
+    fn enable_propagation_configuration() {
+        let config: Config = serde_yaml::from_str("endpoint: default").unwrap();
+        assert!(!config.enable_propagation);
+
+        let config: Config = serde_yaml::from_str(
+            r#"
+            endpoint: default
+            enable_propagation: true
+            "#,
+        )
+        .unwrap();
+        assert!(config.enable_propagation);
+    }
+
+    #[test]
+    fn datadog_propagator_round_trip_sampled() {
+        let propagator = DatadogPropagator::default();
+        let mut carrier: HashMap<String, String> = HashMap::new();
+
+        let trace_id = TraceId::from_u128(0x1234_5678_9abc_def0);
+        let span_id = SpanId::from_u64(42);
+        let span_context = SpanContext::new(
+            trace_id,
+            span_id,
+            TraceFlags::SAMPLED,
+            false,
+            TraceState::default(),
+        );
+        let cx = Context::new().with_remote_span_context(span_context.clone());
+
+        propagator.inject_context(&cx, &mut carrier);
+        assert_eq!(
+            Some(&"1311768467463790320".to_string()),
+            carrier.get(DATADOG_TRACE_ID_HEADER)
+        );
+        assert_eq!(Some(&"42".to_string()), carrier.get(DATADOG_PARENT_ID_HEADER));
+        assert_eq!(
+            Some(&"1".to_string()),
+            carrier.get(DATADOG_SAMPLING_PRIORITY_HEADER)
+        );
+
+        let extracted_cx = propagator.extract_with_context(&Context::new(), &carrier);
+        let extracted = extracted_cx.span().span_context();
+        assert_eq!(span_context.trace_id(), extracted.trace_id());
+        assert_eq!(span_context.span_id(), extracted.span_id());
+        assert!(extracted.is_sampled());
+    }
+
+    #[test]
+    fn datadog_propagator_inject_truncates_to_lower_64_bits() {
+        let propagator = DatadogPropagator::default();
+        let mut carrier: HashMap<String, String> = HashMap::new();
+
+        // High 64 bits are non-zero, unlike the all-zero-high-bits value used above,
+        // so this actually exercises truncation rather than round-tripping trivially.
+        let trace_id = TraceId::from_u128(0xffff_ffff_ffff_ffff_1234_5678_9abc_def0);
+        let span_context = SpanContext::new(
+            trace_id,
+            SpanId::from_u64(42),
+            TraceFlags::SAMPLED,
+            false,
+            TraceState::default(),
+        );
+        let cx = Context::new().with_remote_span_context(span_context);
+
+        propagator.inject_context(&cx, &mut carrier);
+        // only the lower 64 bits (0x1234_5678_9abc_def0) are rendered
+        assert_eq!(
+            Some(&"1311768467463790320".to_string()),
+            carrier.get(DATADOG_TRACE_ID_HEADER)
+        );
+
+        let extracted_cx = propagator.extract_with_context(&Context::new(), &carrier);
+        assert_eq!(
+            TraceId::from_u128(0x1234_5678_9abc_def0),
+            extracted_cx.span().span_context().trace_id()
+        );
+    }
+
+    #[test]
+    fn datadog_propagator_extract_dropped() {
+        let propagator = DatadogPropagator::default();
+        let mut carrier: HashMap<String, String> = HashMap::new();
+        carrier.insert(DATADOG_TRACE_ID_HEADER.to_string(), "42".to_string());
+        carrier.insert(DATADOG_PARENT_ID_HEADER.to_string(), "7".to_string());
+        carrier.insert(DATADOG_SAMPLING_PRIORITY_HEADER.to_string(), "-1".to_string());
+
+        let extracted_cx = propagator.extract_with_context(&Context::new(), &carrier);
+        let extracted = extracted_cx.span().span_context();
+        assert_eq!(TraceId::from_u128(42), extracted.trace_id());
+        assert_eq!(SpanId::from_u64(7), extracted.span_id());
+        assert!(!extracted.is_sampled());
+    }
+
+    #[test]
+    fn datadog_propagator_malformed_priority_defaults_to_dropped() {
+        let propagator = DatadogPropagator::default();
+        let mut carrier: HashMap<String, String> = HashMap::new();
+        carrier.insert(DATADOG_TRACE_ID_HEADER.to_string(), "42".to_string());
+        carrier.insert(DATADOG_PARENT_ID_HEADER.to_string(), "7".to_string());
+        carrier.insert(
+            DATADOG_SAMPLING_PRIORITY_HEADER.to_string(),
+            "not-a-number".to_string(),
+        );
+
+        let extracted_cx = propagator.extract_with_context(&Context::new(), &carrier);
+        assert!(!extracted_cx.span().span_context().is_sampled());
+    }
+
+    #[test]
+    fn datadog_propagator_fields() {
+        let propagator = DatadogPropagator::default();
+        let fields: Vec<&str> = propagator.fields().collect();
+        assert_eq!(
+            vec![
+                DATADOG_TRACE_ID_HEADER,
+                DATADOG_PARENT_ID_HEADER,
+                DATADOG_SAMPLING_PRIORITY_HEADER,
+            ],
+            fields
+        );
+    }
 }
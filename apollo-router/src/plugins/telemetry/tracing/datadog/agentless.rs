@@ -0,0 +1,257 @@
+//! Direct-to-Datadog span export for agentless deployments.
+//!
+//! Unlike [`super::Config::apply_agent`], which hands spans to
+//! `opentelemetry_datadog`'s local-agent pipeline (msgpack v0.3/v0.5), this
+//! exporter POSTs spans as protobuf to Datadog's trace intake, matching the
+//! `api/v0.2/traces` wire format used by agentless exporters (e.g. the
+//! Cloudflare Workers Datadog tracer).
+use std::collections::HashMap;
+use std::time::UNIX_EPOCH;
+
+use futures::future::BoxFuture;
+use opentelemetry::sdk::export::trace::ExportResult;
+use opentelemetry::sdk::export::trace::SpanData;
+use opentelemetry::sdk::export::trace::SpanExporter;
+use opentelemetry::trace::Status;
+use opentelemetry::trace::TraceError;
+use prost::Message;
+
+use super::resolve_span_name;
+use super::resolve_span_resource;
+
+/// A single span in Datadog's APM trace protobuf schema, the wire format
+/// POSTed to `api/v0.2/traces`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct PbSpan {
+    #[prost(string, tag = "1")]
+    service: String,
+    #[prost(string, tag = "2")]
+    name: String,
+    #[prost(string, tag = "3")]
+    resource: String,
+    #[prost(uint64, tag = "4")]
+    trace_id: u64,
+    #[prost(uint64, tag = "5")]
+    span_id: u64,
+    #[prost(uint64, tag = "6")]
+    parent_id: u64,
+    #[prost(int64, tag = "7")]
+    start: i64,
+    #[prost(int64, tag = "8")]
+    duration: i64,
+    #[prost(int32, tag = "9")]
+    error: i32,
+    #[prost(map = "string, string", tag = "10")]
+    meta: HashMap<String, String>,
+    #[prost(map = "string, double", tag = "11")]
+    metrics: HashMap<String, f64>,
+    #[prost(string, tag = "12")]
+    r#type: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct PbTrace {
+    #[prost(message, repeated, tag = "1")]
+    spans: Vec<PbSpan>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct PbTracePayload {
+    #[prost(string, tag = "1")]
+    host_name: String,
+    #[prost(string, tag = "2")]
+    env: String,
+    #[prost(message, repeated, tag = "3")]
+    traces: Vec<PbTrace>,
+}
+
+/// Ships spans directly to Datadog's trace intake over HTTPS as protobuf.
+#[derive(Clone, Debug)]
+pub(crate) struct AgentlessSpanExporter {
+    client: reqwest::Client,
+    endpoint: String,
+    service_name: String,
+    span_name_mappings: HashMap<String, String>,
+    resource_mappings: HashMap<String, String>,
+}
+
+impl AgentlessSpanExporter {
+    pub(crate) fn new(
+        client: reqwest::Client,
+        endpoint: String,
+        service_name: String,
+        span_name_mappings: HashMap<String, String>,
+        resource_mappings: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            client,
+            endpoint,
+            service_name,
+            span_name_mappings,
+            resource_mappings,
+        }
+    }
+
+    fn encode(&self, batch: &[SpanData]) -> Vec<u8> {
+        let mut traces: HashMap<u64, PbTrace> = HashMap::new();
+        for span in batch {
+            let trace_id = span.span_context.trace_id().to_u128() as u64;
+            let start = span
+                .start_time
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos() as i64;
+            let duration = span
+                .end_time
+                .duration_since(span.start_time)
+                .unwrap_or_default()
+                .as_nanos() as i64;
+
+            let pb_span = PbSpan {
+                service: self.service_name.clone(),
+                name: resolve_span_name(&self.span_name_mappings, span.name.as_ref()).to_string(),
+                resource: resolve_span_resource(
+                    &self.resource_mappings,
+                    &span.attributes,
+                    span.name.as_ref(),
+                )
+                .to_string(),
+                trace_id,
+                span_id: span.span_context.span_id().to_u64(),
+                parent_id: span.parent_span_id.to_u64(),
+                start,
+                duration,
+                error: matches!(span.status, Status::Error { .. }) as i32,
+                meta: HashMap::new(),
+                metrics: HashMap::new(),
+                r#type: String::new(),
+            };
+
+            traces
+                .entry(trace_id)
+                .or_insert_with(|| PbTrace { spans: Vec::new() })
+                .spans
+                .push(pb_span);
+        }
+
+        PbTracePayload {
+            host_name: String::new(),
+            env: String::new(),
+            traces: traces.into_values().collect(),
+        }
+        .encode_to_vec()
+    }
+}
+
+impl SpanExporter for AgentlessSpanExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+        let body = self.encode(&batch);
+        let client = self.client.clone();
+        let endpoint = self.endpoint.clone();
+        Box::pin(async move {
+            let response = client
+                .post(endpoint)
+                .header(reqwest::header::CONTENT_TYPE, "application/x-protobuf")
+                .body(body)
+                .send()
+                .await
+                .map_err(|e| TraceError::from(e.to_string()))?;
+            response
+                .error_for_status()
+                .map_err(|e| TraceError::from(e.to_string()))?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use std::time::SystemTime;
+
+    use opentelemetry::sdk::trace::EvictedHashMap;
+    use opentelemetry::sdk::trace::EvictedQueue;
+    use opentelemetry::trace::SpanContext;
+    use opentelemetry::trace::SpanId;
+    use opentelemetry::trace::SpanKind;
+    use opentelemetry::trace::TraceFlags;
+    use opentelemetry::trace::TraceId;
+    use opentelemetry::trace::TraceState;
+    use opentelemetry::Key;
+
+    use super::*;
+
+    fn exporter() -> AgentlessSpanExporter {
+        AgentlessSpanExporter::new(
+            reqwest::Client::new(),
+            "https://trace.agent.datadoghq.com/api/v0.2/traces".to_string(),
+            "apollo-router".to_string(),
+            HashMap::from([("subgraph".to_string(), "subgraph.custom".to_string())]),
+            HashMap::new(),
+        )
+    }
+
+    fn span(trace_id: u128, span_id: u64, name: &'static str, operation: &str) -> SpanData {
+        let mut attributes = EvictedHashMap::new(10, name);
+        attributes.insert(Key::new("graphql.operation.name").string(operation.to_string()));
+
+        SpanData {
+            span_context: SpanContext::new(
+                TraceId::from_u128(trace_id),
+                SpanId::from_u64(span_id),
+                TraceFlags::SAMPLED,
+                false,
+                TraceState::default(),
+            ),
+            parent_span_id: SpanId::from_u64(0),
+            span_kind: SpanKind::Internal,
+            name: name.into(),
+            start_time: SystemTime::UNIX_EPOCH + Duration::from_secs(1),
+            end_time: SystemTime::UNIX_EPOCH + Duration::from_millis(1_500),
+            attributes,
+            events: EvictedQueue::new(0),
+            links: EvictedQueue::new(0),
+            status: Status::Ok,
+            resource: None,
+            instrumentation_lib: Default::default(),
+        }
+    }
+
+    #[test]
+    fn encodes_empty_batch_to_empty_payload() {
+        assert!(exporter().encode(&[]).is_empty());
+    }
+
+    #[test]
+    fn encode_maps_names_and_groups_spans_by_trace_id() {
+        let trace_id = 0x1234_5678_9abc_def0;
+        let batch = vec![
+            span(trace_id, 1, "subgraph", "MyQuery"),
+            span(trace_id, 2, "execution", "MyQuery"),
+        ];
+
+        let bytes = exporter().encode(&batch);
+        let payload = PbTracePayload::decode(bytes.as_slice()).unwrap();
+
+        // both spans share a trace id, so they're grouped into a single trace
+        assert_eq!(1, payload.traces.len());
+        let spans = &payload.traces[0].spans;
+        assert_eq!(2, spans.len());
+
+        let subgraph_span = spans.iter().find(|s| s.span_id == 1).unwrap();
+        // overridden via span_name_mappings
+        assert_eq!("subgraph.custom", subgraph_span.name);
+        // resolved via the built-in "subgraph" -> "graphql.operation.name" resource mapping
+        assert_eq!("MyQuery", subgraph_span.resource);
+        assert_eq!(trace_id as u64, subgraph_span.trace_id);
+        assert_eq!(1_000_000_000, subgraph_span.start);
+        assert_eq!(500_000_000, subgraph_span.duration);
+        assert_eq!(0, subgraph_span.error);
+
+        let execution_span = spans.iter().find(|s| s.span_id == 2).unwrap();
+        // resolved via the built-in SPAN_NAME_MAPPING default
+        assert_eq!("supergraph.execute", execution_span.name);
+        // "execution" has no resource mapping, so it falls back to the raw span name
+        assert_eq!("execution", execution_span.resource);
+    }
+}